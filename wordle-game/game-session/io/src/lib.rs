@@ -14,6 +14,54 @@ impl Metadata for GameSessionMetadata {
     type State = Out<GameSessionState>;
 }
 
+pub struct WordleMetadata;
+
+impl Metadata for WordleMetadata {
+    type Init = In<WordleInit>;
+    type Handle = InOut<WordleAction, WordleEvent>;
+    type Reply = ();
+    type Others = ();
+    type Signal = ();
+    type State = Out<WordleState>;
+}
+
+#[derive(Debug, Default, Clone, Encode, Decode, TypeInfo)]
+pub struct WordleInit {
+    pub word_length: u8,
+    pub words: Vec<String>,
+}
+
+impl Validatable for WordleInit {
+    fn validate(&self) -> bool {
+        self.word_length > 0
+            && !self.words.is_empty()
+            && self.words.iter().all(|word| {
+                word.len() == self.word_length as usize && word.chars().all(|c| c.is_lowercase())
+            })
+    }
+
+    fn validation_error(&self) -> &'static str {
+        "Invalid wordle dictionary: words must be non-empty, lowercase and match the configured length"
+    }
+}
+
+// No `solutions` field: `state()` is a permissionless query, so publishing
+// the secret word would let a player read the answer before guessing it.
+#[derive(Debug, Default, Clone, Encode, Decode, TypeInfo)]
+pub struct WordleState {
+    pub word_length: u8,
+    pub words: Vec<String>,
+}
+
+/// Rejects a guess that isn't one of the wordle program's configured words.
+pub fn validate_word_in_list(word: &str, words: &[String]) -> Result<(), &'static str> {
+    if words.iter().any(|candidate| candidate == word) {
+        Ok(())
+    } else {
+        Err("WordNotInWordlist")
+    }
+}
+
 pub trait GameResult {
     fn is_win(&self) -> bool;
     fn is_lose(&self) -> bool;
@@ -37,21 +85,92 @@ pub trait Validatable {
 #[derive(Debug, Default, Clone, Encode, Decode, TypeInfo)]
 pub struct GameSessionState {
     pub wordle_program_id: ActorId,
+    pub word_length: u8,
+    pub max_tries: u8,
+    pub timeout_blocks: u32,
+    pub words: Vec<String>,
     pub game_sessions: Vec<(ActorId, SessionInfo)>,
+    pub user_stats: Vec<(ActorId, UserStats)>,
+    pub global_stats: GlobalStats,
 }
 
+/// Per-user totals plus a win-count-by-tries-used distribution (index `i`
+/// holds the number of wins that took `i + 1` guesses).
+#[derive(Debug, Default, Clone, Encode, Decode, TypeInfo)]
+pub struct UserStats {
+    pub games_played: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub guess_distribution: Vec<u32>,
+}
+
+impl UserStats {
+    pub fn record_win(&mut self, tries_used: u8) {
+        self.games_played += 1;
+        self.wins += 1;
+        let index = tries_used.saturating_sub(1) as usize;
+        if self.guess_distribution.len() <= index {
+            self.guess_distribution.resize(index + 1, 0);
+        }
+        self.guess_distribution[index] += 1;
+    }
+
+    pub fn record_loss(&mut self) {
+        self.games_played += 1;
+        self.losses += 1;
+    }
+}
+
+/// Totals across every user, updated alongside `UserStats`.
+#[derive(Debug, Default, Clone, Encode, Decode, TypeInfo)]
+pub struct GlobalStats {
+    pub games_played: u32,
+    pub wins: u32,
+    pub losses: u32,
+}
+
+impl GlobalStats {
+    pub fn record_win(&mut self) {
+        self.games_played += 1;
+        self.wins += 1;
+    }
+
+    pub fn record_loss(&mut self) {
+        self.games_played += 1;
+        self.losses += 1;
+    }
+}
+
+/// Upper bound on `GameSessionInit::word_length`, past which a board is
+/// impractical to render or play.
+pub const MAX_WORD_LENGTH: u8 = 32;
+
+/// `words` must be deployed with the exact same contents as the paired
+/// wordle program's `WordleInit::words` — the two programs can't cross-check
+/// this at init time, since game-session has no way to read wordle's state.
 #[derive(Debug, Default, Clone, Encode, Decode, TypeInfo)]
 pub struct GameSessionInit {
     pub wordle_program_id: ActorId,
+    pub word_length: u8,
+    pub max_tries: u8,
+    pub timeout_blocks: u32,
+    pub words: Vec<String>,
 }
 
 impl Validatable for GameSessionInit {
     fn validate(&self) -> bool {
         !self.wordle_program_id.is_zero()
+            && (1..=MAX_WORD_LENGTH).contains(&self.word_length)
+            && self.max_tries > 0
+            && self.timeout_blocks > 0
+            && !self.words.is_empty()
+            && self.words.iter().all(|word| {
+                word.len() == self.word_length as usize && word.chars().all(|c| c.is_lowercase())
+            })
     }
 
     fn validation_error(&self) -> &'static str {
-        "Invalid wordle_program_id"
+        "Invalid wordle_program_id, word_length, max_tries, timeout_blocks or words"
     }
 }
 
@@ -59,6 +178,7 @@ impl Validatable for GameSessionInit {
 pub enum GameSessionAction {
     StartGame,
     CheckWord(String),
+    Hint,
     CheckGameStatus {
         user: ActorId,
         session_id: MessageId,
@@ -87,10 +207,85 @@ impl WordleAction {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum LetterStatus {
+    Correct,
+    Present,
+    Absent,
+}
+
+/// Runs the standard two-pass Wordle comparison: marks exact-position matches
+/// first, then resolves remaining letters against what's left of the
+/// solution's letter frequencies so duplicate letters are scored correctly.
+pub fn compute_letter_statuses(guess: &str, solution: &str) -> Vec<LetterStatus> {
+    let guess_chars: Vec<char> = guess.chars().collect();
+    let solution_chars: Vec<char> = solution.chars().collect();
+    let len = guess_chars.len();
+    let mut statuses = vec![LetterStatus::Absent; len];
+    let mut remaining: HashMap<char, u32> = HashMap::new();
+
+    for i in 0..len {
+        if guess_chars[i] == solution_chars[i] {
+            statuses[i] = LetterStatus::Correct;
+        } else {
+            *remaining.entry(solution_chars[i]).or_insert(0) += 1;
+        }
+    }
+
+    for i in 0..len {
+        if statuses[i] == LetterStatus::Correct {
+            continue;
+        }
+        if let Some(count) = remaining.get_mut(&guess_chars[i]) {
+            if *count > 0 {
+                statuses[i] = LetterStatus::Present;
+                *count -= 1;
+            }
+        }
+    }
+
+    statuses
+}
+
+#[cfg(test)]
+extern crate std;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn correct_guess_marks_every_letter_correct() {
+        let statuses = compute_letter_statuses("horse", "horse");
+        assert_eq!(statuses, vec![LetterStatus::Correct; 5]);
+    }
+
+    #[test]
+    fn duplicate_letter_in_guess_is_capped_by_solution_count() {
+        // The solution has a single 'e'; the guess repeats 'e' at positions
+        // 0 and 1. Only the first occurrence may claim that 'e' as `Present`
+        // — the second must fall back to `Absent` instead of also matching.
+        let statuses = compute_letter_statuses("eeabc", "abcde");
+        assert_eq!(
+            statuses,
+            vec![
+                LetterStatus::Present,
+                LetterStatus::Absent,
+                LetterStatus::Present,
+                LetterStatus::Present,
+                LetterStatus::Present,
+            ]
+        );
+    }
+}
+
 #[derive(Debug, Clone, Encode, Decode, TypeInfo)]
 pub enum GameSessionEvent {
     StartSuccess,
     CheckWordResult {
+        statuses: Vec<LetterStatus>,
+        // Deprecated: derived from `statuses`, kept for one release so
+        // existing clients don't break while they migrate.
         correct_positions: Vec<u8>,
         contained_in_word: Vec<u8>,
         #[codec(skip)]
@@ -98,6 +293,9 @@ pub enum GameSessionEvent {
         #[codec(skip)]
         contains: Vec<u8>,
     },
+    Hint {
+        candidates: Vec<String>,
+    },
     GameOver(GameStatus),
 }
 
@@ -107,6 +305,100 @@ impl GameSessionEvent {
     }
 }
 
+/// Maximum number of candidate words a `Hint` reply carries.
+pub const HINT_CANDIDATES_LIMIT: usize = 10;
+
+/// Whether `word` is still consistent with every guess/status pair in `history`.
+fn matches_history(word: &str, history: &[(String, Vec<LetterStatus>)]) -> bool {
+    let word_chars: Vec<char> = word.chars().collect();
+
+    for (guess, statuses) in history {
+        let guess_chars: Vec<char> = guess.chars().collect();
+        if guess_chars.len() != word_chars.len() || statuses.len() != word_chars.len() {
+            continue;
+        }
+
+        let mut max_allowed: HashMap<char, u32> = HashMap::new();
+        for (i, status) in statuses.iter().enumerate() {
+            if !matches!(status, LetterStatus::Absent) {
+                *max_allowed.entry(guess_chars[i]).or_insert(0) += 1;
+            }
+        }
+
+        for (i, status) in statuses.iter().enumerate() {
+            match status {
+                LetterStatus::Correct => {
+                    if word_chars[i] != guess_chars[i] {
+                        return false;
+                    }
+                }
+                LetterStatus::Present => {
+                    if word_chars[i] == guess_chars[i] || !word_chars.contains(&guess_chars[i]) {
+                        return false;
+                    }
+                }
+                LetterStatus::Absent => {
+                    if word_chars[i] == guess_chars[i] {
+                        return false;
+                    }
+                    let allowed = max_allowed.get(&guess_chars[i]).copied().unwrap_or(0);
+                    let actual = word_chars.iter().filter(|&&c| c == guess_chars[i]).count() as u32;
+                    if actual > allowed {
+                        return false;
+                    }
+                }
+            }
+        }
+    }
+
+    true
+}
+
+/// Filters `word_list` down to words consistent with `history`, then ranks
+/// the survivors by summed positional letter frequency, most informative first.
+pub fn hint_candidates(word_list: &[String], history: &[(String, Vec<LetterStatus>)]) -> Vec<String> {
+    let survivors: Vec<&str> = word_list
+        .iter()
+        .map(String::as_str)
+        .filter(|word| matches_history(word, history))
+        .collect();
+
+    let mut position_frequency: Vec<HashMap<char, u32>> = Vec::new();
+    for word in &survivors {
+        for (i, c) in word.chars().enumerate() {
+            if position_frequency.len() <= i {
+                position_frequency.push(HashMap::new());
+            }
+            *position_frequency[i].entry(c).or_insert(0) += 1;
+        }
+    }
+
+    let mut ranked: Vec<(&str, u32)> = survivors
+        .into_iter()
+        .map(|word| {
+            let score = word
+                .chars()
+                .enumerate()
+                .map(|(i, c)| {
+                    position_frequency
+                        .get(i)
+                        .and_then(|freq| freq.get(&c))
+                        .copied()
+                        .unwrap_or(0)
+                })
+                .sum();
+            (word, score)
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.1.cmp(&a.1));
+
+    ranked
+        .into_iter()
+        .take(HINT_CANDIDATES_LIMIT)
+        .map(|(word, _)| word.to_string())
+        .collect()
+}
+
 #[derive(Debug, Clone, Encode, Decode, TypeInfo)]
 pub enum GameStatus {
     Win,
@@ -120,8 +412,7 @@ pub enum WordleEvent {
     },
     WordChecked {
         user: ActorId,
-        correct_positions: Vec<u8>,
-        contained_in_word: Vec<u8>,
+        statuses: Vec<LetterStatus>,
     },
 }
 
@@ -134,9 +425,12 @@ impl WordleEvent {
 
     pub fn has_guessed(&self) -> bool {
         match self {
-            Self::WordChecked {
-                correct_positions, ..
-            } => correct_positions.len() == 5 && correct_positions.iter().all(|&pos| pos < 5),
+            Self::WordChecked { statuses, .. } => {
+                !statuses.is_empty()
+                    && statuses
+                        .iter()
+                        .all(|status| matches!(status, LetterStatus::Correct))
+            }
             _ => false,
         }
     }
@@ -146,16 +440,27 @@ impl From<&WordleEvent> for GameSessionEvent {
     fn from(wordle_event: &WordleEvent) -> Self {
         match wordle_event {
             WordleEvent::GameStarted { .. } => GameSessionEvent::StartSuccess,
-            WordleEvent::WordChecked {
-                correct_positions,
-                contained_in_word,
-                ..
-            } => GameSessionEvent::CheckWordResult {
-                correct_positions: correct_positions.clone(),
-                contained_in_word: contained_in_word.clone(),
-                positions: correct_positions.clone(),
-                contains: contained_in_word.clone(),
-            },
+            WordleEvent::WordChecked { statuses, .. } => {
+                let correct_positions: Vec<u8> = statuses
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, status)| matches!(status, LetterStatus::Correct))
+                    .map(|(i, _)| i as u8)
+                    .collect();
+                let contained_in_word: Vec<u8> = statuses
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, status)| matches!(status, LetterStatus::Present))
+                    .map(|(i, _)| i as u8)
+                    .collect();
+                GameSessionEvent::CheckWordResult {
+                    statuses: statuses.clone(),
+                    positions: correct_positions.clone(),
+                    contains: contained_in_word.clone(),
+                    correct_positions,
+                    contained_in_word,
+                }
+            }
         }
     }
 }
@@ -182,6 +487,12 @@ pub struct SessionInfo {
     pub send_to_wordle_msg_id: MessageId,
     pub tries: u8,
     pub session_status: SessionStatus,
+    /// Every guess made in the current game, paired with the per-letter
+    /// statuses it was scored with, oldest first.
+    pub guess_history: Vec<(String, Vec<LetterStatus>)>,
+    /// The word sent to the wordle program while awaiting its reply, so it
+    /// can be paired up with the returned statuses once the reply arrives.
+    pub pending_guess: Option<String>,
 }
 
 impl SessionInfo {
@@ -238,7 +549,13 @@ impl SessionInfo {
 #[derive(Default, Debug, Clone)]
 pub struct GameSession {
     pub wordle_program_id: ActorId,
+    pub word_length: u8,
+    pub max_tries: u8,
+    pub timeout_blocks: u32,
+    pub words: Vec<String>,
     pub sessions: HashMap<ActorId, SessionInfo>,
+    pub user_stats: HashMap<ActorId, UserStats>,
+    pub global_stats: GlobalStats,
 }
 
 impl GameSession {
@@ -250,9 +567,19 @@ impl GameSession {
         self.wordle_program_id
     }
 
-    pub fn validate_word(word: &str) -> Result<(), &'static str> {
-        if word.len() != 5 {
-            return Err("Word must be 5 characters long");
+    pub fn record_win(&mut self, user: ActorId, tries_used: u8) {
+        self.user_stats.entry(user).or_default().record_win(tries_used);
+        self.global_stats.record_win();
+    }
+
+    pub fn record_loss(&mut self, user: ActorId) {
+        self.user_stats.entry(user).or_default().record_loss();
+        self.global_stats.record_loss();
+    }
+
+    pub fn validate_word(word: &str, word_length: u8) -> Result<(), &'static str> {
+        if word.len() != word_length as usize {
+            return Err("Word length does not match the configured word length");
         }
         if !word.chars().all(|c| c.is_lowercase()) {
             return Err("Word must contain only lowercase letters");
@@ -265,6 +592,10 @@ impl From<GameSessionInit> for GameSession {
     fn from(init: GameSessionInit) -> Self {
         Self {
             wordle_program_id: init.wordle_program_id,
+            word_length: init.word_length,
+            max_tries: init.max_tries,
+            timeout_blocks: init.timeout_blocks,
+            words: init.words,
             ..Default::default()
         }
     }
@@ -274,11 +605,21 @@ impl From<&GameSession> for GameSessionState {
     fn from(game_session: &GameSession) -> Self {
         Self {
             wordle_program_id: game_session.wordle_program_id,
+            word_length: game_session.word_length,
+            max_tries: game_session.max_tries,
+            timeout_blocks: game_session.timeout_blocks,
+            words: game_session.words.clone(),
             game_sessions: game_session
                 .sessions
                 .iter()
                 .map(|(k, v)| (*k, v.clone()))
                 .collect(),
+            user_stats: game_session
+                .user_stats
+                .iter()
+                .map(|(k, v)| (*k, v.clone()))
+                .collect(),
+            global_stats: game_session.global_stats.clone(),
         }
     }
 }
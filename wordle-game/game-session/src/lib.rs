@@ -3,8 +3,6 @@
 use game_session_io::*;
 use gstd::{exec, msg};
 
-const TRIES_LIMIT: u8 = 5;
-
 static mut GAME_SESSION_STATE: Option<GameSession> = None;
 
 #[no_mangle]
@@ -30,6 +28,7 @@ extern "C" fn handle() {
         GameSessionAction::StartGame => {
             let user = msg::source();
             let wordle_id = game_session.wordle_program_id();
+            let timeout_blocks = game_session.timeout_blocks;
             let session_info = game_session.get_or_create_session(user);
             match &session_info.session_status {
                 SessionStatus::ReplyReceived(wordle_event) => {
@@ -45,6 +44,8 @@ extern "C" fn handle() {
                     session_info.session_id = msg::id();
                     session_info.update_message_ids(msg::id(), send_to_wordle_msg_id);
                     session_info.tries = 0;
+                    session_info.guess_history.clear();
+                    session_info.pending_guess = None;
                     session_info.session_status = SessionStatus::InProgress;
                     msg::send_delayed(
                         exec::program_id(),
@@ -53,7 +54,7 @@ extern "C" fn handle() {
                             session_id: msg::id(),
                         },
                         0,
-                        200,
+                        timeout_blocks,
                     )
                     .expect("Error in send_delayed a message");
                     exec::wait();
@@ -66,19 +67,30 @@ extern "C" fn handle() {
         GameSessionAction::CheckWord(word) => {
             let user = msg::source();
             let wordle_id = game_session.wordle_program_id();
+            let word_length = game_session.word_length;
+            let max_tries = game_session.max_tries;
             let session_info = game_session.get_or_create_session(user);
+            let mut terminal: Option<GameStatus> = None;
             match &session_info.session_status {
                 SessionStatus::ReplyReceived(wordle_event) => {
                     let event_copy = wordle_event.clone();
                     let has_guessed = event_copy.has_guessed();
-                    if session_info.increment_tries() == TRIES_LIMIT {
-                        session_info.session_status = SessionStatus::GameOver(GameStatus::Lose);
-                        msg::reply(GameSessionEvent::game_over(GameStatus::Lose), 0)
-                            .expect("Error in sending a reply");
-                    } else if has_guessed {
+                    if let WordleEvent::WordChecked { statuses, .. } = &event_copy {
+                        if let Some(guess) = session_info.pending_guess.take() {
+                            session_info.guess_history.push((guess, statuses.clone()));
+                        }
+                    }
+                    let tries = session_info.increment_tries();
+                    if has_guessed {
                         session_info.session_status = SessionStatus::GameOver(GameStatus::Win);
                         msg::reply(GameSessionEvent::game_over(GameStatus::Win), 0)
                             .expect("Error in sending a reply");
+                        terminal = Some(GameStatus::Win);
+                    } else if tries == max_tries {
+                        session_info.session_status = SessionStatus::GameOver(GameStatus::Lose);
+                        msg::reply(GameSessionEvent::game_over(GameStatus::Lose), 0)
+                            .expect("Error in sending a reply");
+                        terminal = Some(GameStatus::Lose);
                     } else {
                         msg::reply::<GameSessionEvent>((&event_copy).into(), 0)
                             .expect("Error in sending a reply");
@@ -86,7 +98,8 @@ extern "C" fn handle() {
                     }
                 }
                 SessionStatus::InProgress => {
-                    GameSession::validate_word(&word).expect("Invalid word");
+                    GameSession::validate_word(&word, word_length).expect("Invalid word");
+                    session_info.pending_guess = Some(word.clone());
                     let send_to_wordle_msg_id =
                         msg::send(wordle_id, WordleAction::new_check_word(user, word), 0)
                             .expect("Error in sending a message");
@@ -97,8 +110,29 @@ extern "C" fn handle() {
                     panic!("The user is not in the game");
                 }
             }
+            match terminal {
+                Some(GameStatus::Win) => {
+                    let tries_used = game_session
+                        .sessions
+                        .get(&user)
+                        .map(|session_info| session_info.tries)
+                        .unwrap_or_default();
+                    game_session.record_win(user, tries_used);
+                }
+                Some(GameStatus::Lose) => game_session.record_loss(user),
+                None => {}
+            }
+        }
+        GameSessionAction::Hint => {
+            let user = msg::source();
+            let words = game_session.words.clone();
+            let session_info = game_session.get_or_create_session(user);
+            let candidates = hint_candidates(&words, &session_info.guess_history);
+            msg::reply(GameSessionEvent::Hint { candidates }, 0)
+                .expect("Error in sending a reply");
         }
         GameSessionAction::CheckGameStatus { user, session_id } => {
+            let mut timed_out = false;
             if msg::source() == exec::program_id() {
                 if let Some(session_info) = game_session.sessions.get_mut(&user) {
                     if session_id == session_info.session_id
@@ -107,9 +141,13 @@ extern "C" fn handle() {
                         session_info.session_status = SessionStatus::GameOver(GameStatus::Lose);
                         msg::send(user, GameSessionEvent::GameOver(GameStatus::Lose), 0)
                             .expect("Error in sending a reply");
+                        timed_out = true;
                     }
                 }
             }
+            if timed_out {
+                game_session.record_loss(user);
+            }
         }
     }
 }
@@ -20,13 +20,23 @@ fn test_basic_game_flow() {
             .with_id(WORDLE_PROGRAM_ID)
             .build(&system);
 
-    let result = wordle.send_bytes(USER, []);
+    let result = wordle.send(
+        USER,
+        WordleInit {
+            word_length: 5,
+            words: vec!["horse".to_string()],
+        },
+    );
     assert!(!result.main_failed());
 
     let result = game_session.send(
         USER,
         GameSessionInit {
             wordle_program_id: WORDLE_PROGRAM_ID.into(),
+            word_length: 5,
+            max_tries: 5,
+            timeout_blocks: 200,
+            words: vec!["horse".to_string()],
         },
     );
     assert!(!result.main_failed());
@@ -64,13 +74,23 @@ fn test_game_timeout() {
             .with_id(WORDLE_PROGRAM_ID)
             .build(&system);
 
-    let result = wordle.send_bytes(USER, []);
+    let result = wordle.send(
+        USER,
+        WordleInit {
+            word_length: 5,
+            words: vec!["horse".to_string()],
+        },
+    );
     assert!(!result.main_failed());
 
     let result = game_session.send(
         USER,
         GameSessionInit {
             wordle_program_id: WORDLE_PROGRAM_ID.into(),
+            word_length: 5,
+            max_tries: 5,
+            timeout_blocks: 200,
+            words: vec!["horse".to_string()],
         },
     );
     assert!(!result.main_failed());
@@ -101,13 +121,23 @@ fn test_invalid_words() {
             .with_id(WORDLE_PROGRAM_ID)
             .build(&system);
 
-    let result = wordle.send_bytes(USER, []);
+    let result = wordle.send(
+        USER,
+        WordleInit {
+            word_length: 5,
+            words: vec!["horse".to_string()],
+        },
+    );
     assert!(!result.main_failed());
 
     let result = game_session.send(
         USER,
         GameSessionInit {
             wordle_program_id: WORDLE_PROGRAM_ID.into(),
+            word_length: 5,
+            max_tries: 5,
+            timeout_blocks: 200,
+            words: vec!["horse".to_string()],
         },
     );
     assert!(!result.main_failed());
@@ -142,13 +172,35 @@ fn test_multiple_guesses() {
             .with_id(WORDLE_PROGRAM_ID)
             .build(&system);
 
-    let result = wordle.send_bytes(USER, []);
+    let result = wordle.send(
+        USER,
+        WordleInit {
+            word_length: 5,
+            words: vec![
+                "horse".to_string(),
+                "table".to_string(),
+                "chair".to_string(),
+                "mouse".to_string(),
+                "phone".to_string(),
+            ],
+        },
+    );
     assert!(!result.main_failed());
 
     let result = game_session.send(
         USER,
         GameSessionInit {
             wordle_program_id: WORDLE_PROGRAM_ID.into(),
+            word_length: 5,
+            max_tries: 5,
+            timeout_blocks: 200,
+            words: vec![
+                "horse".to_string(),
+                "table".to_string(),
+                "chair".to_string(),
+                "mouse".to_string(),
+                "phone".to_string(),
+            ],
         },
     );
     assert!(!result.main_failed());
@@ -161,9 +213,213 @@ fn test_multiple_guesses() {
     for word in words {
         let result = game_session.send(USER, GameSessionAction::CheckWord(word.to_string()));
         assert!(!result.main_failed());
+
+        // The solution is picked at random from the dictionary, so the game
+        // can end on any one of these guesses (every word in the list gets
+        // tried, so a win is guaranteed by the last one at the latest).
+        // Stop feeding guesses once it's over instead of assuming it takes
+        // all five.
+        let win_log = Log::builder()
+            .dest(USER)
+            .source(GAME_SESSION_PROGRAM_ID)
+            .payload(GameSessionEvent::GameOver(GameStatus::Win));
+        let lose_log = Log::builder()
+            .dest(USER)
+            .source(GAME_SESSION_PROGRAM_ID)
+            .payload(GameSessionEvent::GameOver(GameStatus::Lose));
+        if result.contains(&win_log) || result.contains(&lose_log) {
+            break;
+        }
     }
 }
 
+#[test]
+fn test_hint_narrows_candidates_to_the_solution() {
+    let system = System::new();
+    system.init_logger();
+
+    let game_session =
+        ProgramBuilder::from_file("../target/wasm32-unknown-unknown/debug/game_session.opt.wasm")
+            .with_id(GAME_SESSION_PROGRAM_ID)
+            .build(&system);
+
+    let wordle =
+        ProgramBuilder::from_file("../target/wasm32-unknown-unknown/debug/wordle.opt.wasm")
+            .with_id(WORDLE_PROGRAM_ID)
+            .build(&system);
+
+    // Two candidates that share almost no letters, so a single guess is
+    // enough for `matches_history` to rule one of them out entirely.
+    let words = vec!["apple".to_string(), "zonal".to_string()];
+
+    let result = wordle.send(
+        USER,
+        WordleInit {
+            word_length: 5,
+            words: words.clone(),
+        },
+    );
+    assert!(!result.main_failed());
+
+    let result = game_session.send(
+        USER,
+        GameSessionInit {
+            wordle_program_id: WORDLE_PROGRAM_ID.into(),
+            word_length: 5,
+            max_tries: 5,
+            timeout_blocks: 200,
+            words,
+        },
+    );
+    assert!(!result.main_failed());
+
+    let result = game_session.send(USER, GameSessionAction::StartGame);
+    assert!(!result.main_failed());
+
+    let result = game_session.send(USER, GameSessionAction::CheckWord("apple".to_string()));
+    assert!(!result.main_failed());
+    let win_log = Log::builder()
+        .dest(USER)
+        .source(GAME_SESSION_PROGRAM_ID)
+        .payload(GameSessionEvent::GameOver(GameStatus::Win));
+    // Whichever of the two words the wordle program actually picked as the
+    // solution, guessing "apple" is informative enough that only the true
+    // solution survives `matches_history` — so `Hint` must narrow down to
+    // exactly that one word either way.
+    let solution = if result.contains(&win_log) {
+        "apple"
+    } else {
+        "zonal"
+    };
+
+    let result = game_session.send(USER, GameSessionAction::Hint);
+    let log = Log::builder()
+        .dest(USER)
+        .source(GAME_SESSION_PROGRAM_ID)
+        .payload(GameSessionEvent::Hint {
+            candidates: vec![solution.to_string()],
+        });
+    assert!(!result.main_failed() && result.contains(&log));
+}
+
+#[test]
+fn test_state_tracks_win_and_loss_stats() {
+    let system = System::new();
+    system.init_logger();
+
+    let game_session =
+        ProgramBuilder::from_file("../target/wasm32-unknown-unknown/debug/game_session.opt.wasm")
+            .with_id(GAME_SESSION_PROGRAM_ID)
+            .build(&system);
+
+    let wordle =
+        ProgramBuilder::from_file("../target/wasm32-unknown-unknown/debug/wordle.opt.wasm")
+            .with_id(WORDLE_PROGRAM_ID)
+            .build(&system);
+
+    let result = wordle.send(
+        USER,
+        WordleInit {
+            word_length: 5,
+            words: vec!["horse".to_string()],
+        },
+    );
+    assert!(!result.main_failed());
+
+    let result = game_session.send(
+        USER,
+        GameSessionInit {
+            wordle_program_id: WORDLE_PROGRAM_ID.into(),
+            word_length: 5,
+            max_tries: 5,
+            timeout_blocks: 5,
+            words: vec!["horse".to_string()],
+        },
+    );
+    assert!(!result.main_failed());
+
+    // First game: win on the very first try.
+    let result = game_session.send(USER, GameSessionAction::StartGame);
+    assert!(!result.main_failed());
+    let result = game_session.send(USER, GameSessionAction::CheckWord("horse".to_string()));
+    assert!(!result.main_failed());
+
+    let state: GameSessionState = game_session
+        .read_state(Vec::new())
+        .expect("Unable to read state");
+    assert_eq!(state.global_stats.games_played, 1);
+    assert_eq!(state.global_stats.wins, 1);
+    assert_eq!(state.global_stats.losses, 0);
+    let user_stats = &state
+        .user_stats
+        .iter()
+        .find(|(user, _)| *user == USER.into())
+        .expect("No stats recorded for user")
+        .1;
+    assert_eq!(user_stats.games_played, 1);
+    assert_eq!(user_stats.wins, 1);
+    assert_eq!(user_stats.losses, 0);
+    assert_eq!(user_stats.guess_distribution, vec![1]);
+
+    // Second game: let it time out instead of guessing, so it's recorded as
+    // a loss without touching the guess distribution.
+    let result = game_session.send(USER, GameSessionAction::StartGame);
+    assert!(!result.main_failed());
+    system.spend_blocks(5);
+
+    let state: GameSessionState = game_session
+        .read_state(Vec::new())
+        .expect("Unable to read state");
+    assert_eq!(state.global_stats.games_played, 2);
+    assert_eq!(state.global_stats.wins, 1);
+    assert_eq!(state.global_stats.losses, 1);
+    let user_stats = &state
+        .user_stats
+        .iter()
+        .find(|(user, _)| *user == USER.into())
+        .expect("No stats recorded for user")
+        .1;
+    assert_eq!(user_stats.games_played, 2);
+    assert_eq!(user_stats.wins, 1);
+    assert_eq!(user_stats.losses, 1);
+    assert_eq!(user_stats.guess_distribution, vec![1]);
+}
+
+#[test]
+fn test_check_word_rejects_word_not_in_dictionary() {
+    let system = System::new();
+    system.init_logger();
+
+    let wordle =
+        ProgramBuilder::from_file("../target/wasm32-unknown-unknown/debug/wordle.opt.wasm")
+            .with_id(WORDLE_PROGRAM_ID)
+            .build(&system);
+
+    let result = wordle.send(
+        USER,
+        WordleInit {
+            word_length: 5,
+            words: vec!["horse".to_string()],
+        },
+    );
+    assert!(!result.main_failed());
+
+    let result = wordle.send(USER, WordleAction::StartGame { user: USER.into() });
+    assert!(!result.main_failed());
+
+    // "mouse" is a well-formed 5-letter lowercase word, but it isn't in the
+    // configured dictionary, so it must be rejected with `WordNotInWordlist`
+    // instead of being scored against the solution.
+    let result = wordle.send(
+        USER,
+        WordleAction::CheckWord {
+            user: USER.into(),
+            word: "mouse".to_string(),
+        },
+    );
+    assert!(result.main_failed());
+}
+
 #[test]
 fn test_restart_game() {
     let system = System::new();
@@ -179,13 +435,23 @@ fn test_restart_game() {
             .with_id(WORDLE_PROGRAM_ID)
             .build(&system);
 
-    let result = wordle.send_bytes(USER, []);
+    let result = wordle.send(
+        USER,
+        WordleInit {
+            word_length: 5,
+            words: vec!["horse".to_string()],
+        },
+    );
     assert!(!result.main_failed());
 
     let result = game_session.send(
         USER,
         GameSessionInit {
             wordle_program_id: WORDLE_PROGRAM_ID.into(),
+            word_length: 5,
+            max_tries: 5,
+            timeout_blocks: 200,
+            words: vec!["horse".to_string()],
         },
     );
     assert!(!result.main_failed());
@@ -0,0 +1,79 @@
+#![no_std]
+
+use game_session_io::*;
+use gstd::{collections::HashMap, exec, msg, prelude::*, ActorId};
+
+#[derive(Default)]
+struct Wordle {
+    word_length: u8,
+    words: Vec<String>,
+    solutions: HashMap<ActorId, String>,
+}
+
+impl Wordle {
+    fn pick_solution(&self, user: ActorId) -> String {
+        let mut subject = [0u8; 32];
+        let user_bytes = user.into_bytes();
+        let len = user_bytes.len().min(subject.len());
+        subject[..len].copy_from_slice(&user_bytes[..len]);
+        for (byte, salt) in subject.iter_mut().zip(exec::block_timestamp().to_le_bytes().iter().cycle()) {
+            *byte ^= *salt;
+        }
+        let (random, _) = exec::random(subject).expect("Error getting random number");
+        let index = u32::from_le_bytes(random[..4].try_into().unwrap()) as usize % self.words.len();
+        self.words[index].clone()
+    }
+}
+
+static mut WORDLE_STATE: Option<Wordle> = None;
+
+#[no_mangle]
+extern "C" fn init() {
+    let wordle_init: WordleInit = msg::load().expect("Unable to decode `WordleInit`");
+    if !wordle_init.validate() {
+        panic!("{}", wordle_init.validation_error());
+    }
+    unsafe {
+        WORDLE_STATE = Some(Wordle {
+            word_length: wordle_init.word_length,
+            words: wordle_init.words,
+            solutions: HashMap::new(),
+        });
+    }
+}
+
+#[no_mangle]
+extern "C" fn handle() {
+    let wordle_action: WordleAction = msg::load().expect("Unable to decode `WordleAction`");
+    let wordle = unsafe { WORDLE_STATE.as_mut().expect("Wordle is not initialized") };
+    match wordle_action {
+        WordleAction::StartGame { user } => {
+            let solution = wordle.pick_solution(user);
+            wordle.solutions.insert(user, solution);
+            msg::reply(WordleEvent::GameStarted { user }, 0).expect("Error in sending a reply");
+        }
+        WordleAction::CheckWord { user, word } => {
+            validate_word_in_list(&word, &wordle.words).expect("Invalid word");
+            let solution = wordle
+                .solutions
+                .get(&user)
+                .expect("No active game for user");
+            let statuses = compute_letter_statuses(&word, solution);
+            msg::reply(WordleEvent::WordChecked { user, statuses }, 0)
+                .expect("Error in sending a reply");
+        }
+    }
+}
+
+#[no_mangle]
+extern "C" fn state() {
+    let wordle = unsafe { WORDLE_STATE.as_ref().expect("Wordle is not initialized") };
+    msg::reply::<WordleState>(
+        WordleState {
+            word_length: wordle.word_length,
+            words: wordle.words.clone(),
+        },
+        0,
+    )
+    .expect("failed to encode or reply from `state()`");
+}